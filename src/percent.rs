@@ -0,0 +1,93 @@
+//! percent-encoding and decoding of URL components, following RFC 3986.
+
+use std::borrow::Cow;
+
+/// the reserved-character set a component is encoded against.
+///
+/// Each variant allows the unreserved characters plus the sub-delimiters and
+/// delimiters that may legally appear unescaped in that component. `Query`
+/// and `Fragment` also allow their leading `?` / `#`, which
+/// [`URL::query`](crate::URL::query) and [`URL::hash`](crate::URL::hash) store.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Set {
+    Path,
+    Query,
+    Fragment,
+    Userinfo,
+}
+
+impl Set {
+    /// may `byte` appear literally in this set, or must it be escaped?
+    fn allows(self, byte: u8) -> bool {
+        if matches!(byte,
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~')
+        {
+            return true;
+        }
+        // `%` is passed through so components that already contain `%XX`
+        // escapes round-trip unchanged instead of being double-encoded.
+        if byte == b'%' {
+            return true;
+        }
+        let extra: &[u8] = match self {
+            Set::Path => b"/:@!$&'()*+,;=",
+            Set::Query => b"/?:@!$&'()*+,;=",
+            Set::Fragment => b"/?#:@!$&'()*+,;=",
+            Set::Userinfo => b":!$&'()*+,;=",
+        };
+        extra.contains(&byte)
+    }
+}
+
+/// decode `%XX` sequences into the bytes they denote and re-interpret the
+/// result as UTF-8.
+///
+/// Returns a borrowed [`Cow`] when the input contains no `%`.
+pub fn decode(input: &str) -> Cow<'_, str> {
+    if !input.contains('%') {
+        return Cow::Borrowed(input);
+    }
+    let bytes = input.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            if let (Some(hi), Some(lo)) = (hex_digit(bytes[i + 1]), hex_digit(bytes[i + 2])) {
+                out.push(hi << 4 | lo);
+                i += 3;
+                continue;
+            }
+        }
+        out.push(bytes[i]);
+        i += 1;
+    }
+    Cow::Owned(String::from_utf8_lossy(&out).into_owned())
+}
+
+/// encode any byte of `input` outside `set` as uppercase `%XX`.
+///
+/// Returns a borrowed [`Cow`] when every byte is already allowed.
+pub fn encode(input: &str, set: Set) -> Cow<'_, str> {
+    if input.bytes().all(|b| set.allows(b)) {
+        return Cow::Borrowed(input);
+    }
+    let mut out = String::with_capacity(input.len());
+    for &b in input.as_bytes() {
+        if set.allows(b) {
+            out.push(b as char);
+        } else {
+            out.push_str(&format!("%{:02X}", b));
+        }
+    }
+    Cow::Owned(out)
+}
+
+/// value of a single ASCII hex digit, or `None` if it is not one.
+fn hex_digit(b: u8) -> Option<u8> {
+    match b {
+        b'0'..=b'9' => Some(b - b'0'),
+        b'a'..=b'f' => Some(b - b'a' + 10),
+        b'A'..=b'F' => Some(b - b'A' + 10),
+        _ => None,
+    }
+}
@@ -1,4 +1,4 @@
-use simple_url_parser::URL;
+use simple_url_parser::{Host, URL};
 
 #[test]
 fn test_url_parser() {
@@ -8,8 +8,8 @@ fn test_url_parser() {
     assert_eq!(url_obj.scheme, "https");
     assert_eq!(url_obj.username, "lb");
     assert_eq!(url_obj.password, "123456");
-    assert_eq!(url_obj.host, "www.google.com");
-    assert_eq!(url_obj.port, "123");
+    assert_eq!(url_obj.host, Host::Domain("www.google.com".to_owned()));
+    assert_eq!(url_obj.port, Some(123));
     assert_eq!(url_obj.path, "/blog/01");
     assert_eq!(url_obj.query, "?a=1&b=2");
     assert_eq!(url_obj.hash, "#132456");
@@ -18,13 +18,111 @@ fn test_url_parser() {
     assert_eq!(url_str, mock_url);
 }
 
+#[test]
+fn test_parse_owned_string() {
+    let owned = String::from("http://example.org");
+    let url_obj = URL::parse(&owned).unwrap();
+    assert_eq!(url_obj.host, Host::Domain("example.org".to_owned()));
+}
+
+#[test]
+fn test_path_segments() {
+    let mut url_obj = URL::parse("http://host/blog/01").unwrap();
+    assert_eq!(
+        url_obj.path_segments().unwrap().collect::<Vec<_>>(),
+        vec!["blog", "01"]
+    );
+
+    url_obj.push_segment("a b");
+    assert_eq!(url_obj.path, "/blog/01/a%20b");
+    url_obj.pop_segment();
+    assert_eq!(url_obj.path, "/blog/01");
+}
+
+#[test]
+fn test_percent_roundtrip() {
+    let url_obj = URL::parse("http://host/a b?x=1 2#h i").unwrap();
+    assert_eq!(url_obj.path, "/a b");
+    assert_eq!(url_obj.decoded_path(), "/a b");
+    assert_eq!(
+        URL::stringify(&url_obj),
+        "http://host/a%20b?x=1%202#h%20i"
+    );
+}
+
+#[test]
+fn test_pre_encoded_roundtrip() {
+    let mock_url = "http://host/a%20b?x=%26#frag";
+    let url_obj = URL::parse(mock_url).unwrap();
+    assert_eq!(URL::stringify(&url_obj), mock_url);
+}
+
+#[test]
+fn test_from_str() {
+    let url_obj: URL = "http://example.org".parse().unwrap();
+    assert_eq!(url_obj.host, Host::Domain("example.org".to_owned()));
+}
+
+#[test]
+fn test_host_variants() {
+    let url_obj = URL::parse("http://127.0.0.1/").unwrap();
+    assert_eq!(url_obj.host, Host::Ipv4("127.0.0.1".parse().unwrap()));
+    assert_eq!(url_obj.port, None);
+
+    let url_obj = URL::parse("http://[::1]:8080/").unwrap();
+    assert_eq!(url_obj.host, Host::Ipv6("::1".parse().unwrap()));
+    assert_eq!(url_obj.port, Some(8080));
+    assert_eq!(URL::stringify(&url_obj), "http://[::1]:8080/");
+}
+
+#[test]
+fn test_join() {
+    let base = URL::parse("https://www.google.com/a/b/c?x=1#top").unwrap();
+
+    let absolute = base.join("/resources/testharness.js").unwrap();
+    assert_eq!(absolute.path, "/resources/testharness.js");
+    assert_eq!(absolute.query, "");
+    assert_eq!(absolute.hash, "");
+
+    let relative = base.join("../d").unwrap();
+    assert_eq!(relative.path, "/a/d");
+
+    let fragment = base.join("#bottom").unwrap();
+    assert_eq!(fragment.path, "/a/b/c");
+    assert_eq!(fragment.query, "?x=1");
+    assert_eq!(fragment.hash, "#bottom");
+}
+
+#[test]
+fn test_query_pairs() {
+    let url_obj = URL::parse("https://www.google.com/blog/01?a=1&b=2").unwrap();
+    assert_eq!(
+        url_obj.query_pairs(),
+        vec![
+            ("a".to_owned(), "1".to_owned()),
+            ("b".to_owned(), "2".to_owned())
+        ]
+    );
+
+    let mut url_obj = url_obj;
+    url_obj.set_query_pairs(&[("name", "a b"), ("q", "x&y")]);
+    assert_eq!(url_obj.query, "?name=a+b&q=x%26y");
+    assert_eq!(
+        url_obj.query_pairs(),
+        vec![
+            ("name".to_owned(), "a b".to_owned()),
+            ("q".to_owned(), "x&y".to_owned())
+        ]
+    );
+}
+
 #[test]
 fn test_fix_issue_3() {
     let mock_url = "http://example.org";
     let url_obj = URL::parse(mock_url).unwrap();
 
-    assert_eq!(url_obj.scheme, "http:");
-    assert_eq!(url_obj.host, "example.org");
+    assert_eq!(url_obj.scheme, "http");
+    assert_eq!(url_obj.host, Host::Domain("example.org".to_owned()));
 
     let url_str = URL::stringify(&url_obj);
     assert_eq!(url_str, mock_url);